@@ -0,0 +1,65 @@
+use std::array::TryFromSliceError;
+
+/// The error type returned by fallible operations across this crate.
+#[derive(Debug)]
+pub enum Error {
+    /// A BIP-39 mnemonic could not be parsed.
+    Mnemonic(bip39::Error),
+    /// A byte slice was the wrong length to convert into a fixed-size array.
+    SliceConversion(TryFromSliceError),
+    /// A `ring` AEAD or KDF operation failed.
+    Crypto(ring::error::Unspecified),
+    /// A secp256k1 key, signature, or recovery-id operation failed.
+    Secp256k1(secp256k1::Error),
+    /// An encrypted keystore envelope was malformed, or used an unsupported
+    /// KDF, cipher, or envelope version.
+    InvalidKeystoreFormat(&'static str),
+    /// A vanity-address search pattern was empty or used characters outside
+    /// the c32 alphabet.
+    InvalidVanityPattern(&'static str),
+    /// A vanity-address search exhausted its attempt budget without finding
+    /// a match.
+    VanityPatternNotFound,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Mnemonic(err) => write!(f, "invalid mnemonic: {err}"),
+            Self::SliceConversion(err) => write!(f, "slice conversion failed: {err}"),
+            Self::Crypto(err) => write!(f, "cryptographic operation failed: {err}"),
+            Self::Secp256k1(err) => write!(f, "secp256k1 error: {err}"),
+            Self::InvalidKeystoreFormat(reason) => write!(f, "invalid keystore format: {reason}"),
+            Self::InvalidVanityPattern(reason) => write!(f, "invalid vanity pattern: {reason}"),
+            Self::VanityPatternNotFound => {
+                write!(f, "vanity pattern not found within attempt budget")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<bip39::Error> for Error {
+    fn from(err: bip39::Error) -> Self {
+        Self::Mnemonic(err)
+    }
+}
+
+impl From<TryFromSliceError> for Error {
+    fn from(err: TryFromSliceError) -> Self {
+        Self::SliceConversion(err)
+    }
+}
+
+impl From<ring::error::Unspecified> for Error {
+    fn from(err: ring::error::Unspecified) -> Self {
+        Self::Crypto(err)
+    }
+}
+
+impl From<secp256k1::Error> for Error {
+    fn from(err: secp256k1::Error) -> Self {
+        Self::Secp256k1(err)
+    }
+}
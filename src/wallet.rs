@@ -12,19 +12,407 @@ use crate::StacksPublicKey;
 use rand::Rng;
 use ring::aead;
 use ring::pbkdf2;
+use scrypt::Params as ScryptParams;
+use secp256k1::ecdsa::RecoverableSignature;
+use secp256k1::ecdsa::RecoveryId;
+use secp256k1::Message;
+use secp256k1::Secp256k1;
+use sha2::Digest;
+use sha2::Sha256;
+use zeroize::Zeroize;
+use zeroize::Zeroizing;
 
 pub(crate) const STX_DERIVATION_PATH: &str = "m/44'/5757'/0'/0";
 
 pub type StacksAccounts = std::collections::HashMap<u32, StacksAccount>;
 
-/// A `StacksAccount` instance, which contains a public key, a private key, and a derivation index.
+/// Magic byte identifying a `stacks.rs` encrypted keystore envelope.
+const KEYSTORE_MAGIC: u8 = 0x53;
+
+/// Envelope version written by [`StacksWallet::encrypt_key`]: the AEAD
+/// plaintext holds only the root chain code and private key.
+///
+/// `from_encrypted_key`/`from_encrypted_wallet` dispatch on this byte, so
+/// older versions keep decrypting even after the default KDF/cipher
+/// parameters or the plaintext layout change.
+const KEYSTORE_VERSION_ROOT_ONLY: u8 = 1;
+
+/// Envelope version written by [`StacksWallet::encrypt_wallet`]: the AEAD
+/// plaintext additionally holds the length-prefixed set of derived account
+/// indices (and optional labels), so restoring it reproduces the exact
+/// working set of accounts.
+const KEYSTORE_VERSION_FULL: u8 = 2;
+
+/// Byte length of a secp256k1 private key, as stored in a keystore envelope.
+const PRIVATE_KEY_BYTE_SIZE: usize = 32;
+
+/// Byte length of the AES-GCM authentication tag appended to the ciphertext.
+const AEAD_TAG_LEN: usize = 16;
+
+/// Default PBKDF2-HMAC-SHA512 iteration count used by [`StacksWallet::encrypt_key`].
+pub const DEFAULT_PBKDF2_ITERATIONS: u32 = 100_000;
+
+/// Default scrypt cost parameter (`N = 2^15`) used by [`KdfParams::default_scrypt`].
+pub const DEFAULT_SCRYPT_LOG_N: u8 = 15;
+
+/// Default scrypt block size parameter (`r = 8`) used by [`KdfParams::default_scrypt`].
+pub const DEFAULT_SCRYPT_R: u32 = 8;
+
+/// Default scrypt parallelization parameter (`p = 1`) used by [`KdfParams::default_scrypt`].
+pub const DEFAULT_SCRYPT_P: u32 = 1;
+
+/// Length, in bytes, of the random salt written into the keystore envelope.
+const SALT_LEN: usize = 16;
+
+/// Prefix prepended to a message before hashing in [`StacksAccount::sign_message`],
+/// matching the convention used by Stacks wallets to domain-separate signed
+/// messages from raw transactions.
+const MESSAGE_SIGNING_PREFIX: &str = "\x17Stacks Signed Message:\n";
+
+/// Prefix prepended to a SIP-018 structured-data hash before signing, per the
+/// SIP-018 specification.
+const SIP018_PREFIX: [u8; 6] = *b"SIP018";
+
+/// Hashes `message` the way Stacks wallets do before signing it: the
+/// [`MESSAGE_SIGNING_PREFIX`], the message's length as ASCII digits, then the
+/// message itself, all SHA-256 hashed.
+fn hash_message(message: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(MESSAGE_SIGNING_PREFIX.as_bytes());
+    hasher.update(message.len().to_string().as_bytes());
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
+/// Hashes a SIP-018 `(domain_hash, message_hash)` pair into the final digest
+/// that gets signed, domain-separating it from both raw messages and
+/// transactions so a signature can't be replayed across apps or networks.
+///
+/// `domain_hash` and `message_hash` are the Clarity-value hashes of the
+/// signing domain and payload respectively; computing them from a Clarity
+/// value is the caller's responsibility.
+fn hash_structured_data(domain_hash: &[u8; 32], message_hash: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(SIP018_PREFIX);
+    hasher.update(domain_hash);
+    hasher.update(message_hash);
+    hasher.finalize().into()
+}
+
+/// Signs a 32-byte digest, returning a recoverable secp256k1 signature in the
+/// `[v, r (32 bytes), s (32 bytes)]` (VRS) form Stacks verifiers expect.
+fn sign_recoverable_hash(private_key: &StacksPrivateKey, hash: &[u8; 32]) -> Result<[u8; 65], Error> {
+    let secp = Secp256k1::signing_only();
+    let message = Message::from_slice(hash)?;
+    let signature = secp.sign_ecdsa_recoverable(&message, private_key);
+    let (recovery_id, compact) = signature.serialize_compact();
+
+    #[allow(clippy::unwrap_used)]
+    let mut result = [0u8; 65];
+    result[0] = u8::try_from(recovery_id.to_i32()).unwrap();
+    result[1..].copy_from_slice(&compact);
+    Ok(result)
+}
+
+/// Verifies a `sign_recoverable_hash` signature against `public_key`.
+fn verify_recoverable_hash(
+    public_key: &StacksPublicKey,
+    hash: &[u8; 32],
+    signature: &[u8; 65],
+) -> Result<bool, Error> {
+    let recovery_id = RecoveryId::from_i32(i32::from(signature[0]))?;
+    let recoverable = RecoverableSignature::from_compact(&signature[1..], recovery_id)?;
+    let message = Message::from_slice(hash)?;
+
+    let secp = Secp256k1::verification_only();
+    Ok(secp
+        .verify_ecdsa(&message, &recoverable.to_standard(), public_key)
+        .is_ok())
+}
+
+/// A passphrase wrapper that zeroes its backing bytes on drop and never
+/// prints its contents, so a passphrase doesn't linger in memory or leak
+/// into logs after `encrypt_key`/`from_encrypted_key` return.
+pub struct SafePassword(Zeroizing<Vec<u8>>);
+
+impl SafePassword {
+    fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<&str> for SafePassword {
+    fn from(value: &str) -> Self {
+        Self(Zeroizing::new(value.as_bytes().to_vec()))
+    }
+}
+
+impl From<String> for SafePassword {
+    fn from(value: String) -> Self {
+        Self(Zeroizing::new(value.into_bytes()))
+    }
+}
+
+impl std::fmt::Debug for SafePassword {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SafePassword").field(&"..").finish()
+    }
+}
+
+/// Identifies the key-derivation function recorded in a keystore envelope.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+enum KdfId {
+    Pbkdf2HmacSha512 = 0,
+    Scrypt = 1,
+}
+
+impl KdfId {
+    fn from_u8(value: u8) -> Result<Self, Error> {
+        match value {
+            0 => Ok(Self::Pbkdf2HmacSha512),
+            1 => Ok(Self::Scrypt),
+            _ => Err(Error::InvalidKeystoreFormat("unknown KDF identifier")),
+        }
+    }
+}
+
+/// The symmetric cipher used to encrypt a keystore envelope's plaintext,
+/// selectable when calling [`StacksWallet::encrypt_key_with_params`] and
+/// recorded in the envelope so `from_encrypted_key` can reproduce it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CipherId {
+    /// AES-128-GCM, keyed and nonced from the low 28 bytes of the KDF output.
+    Aes128Gcm = 0,
+}
+
+impl Default for CipherId {
+    fn default() -> Self {
+        Self::Aes128Gcm
+    }
+}
+
+impl CipherId {
+    fn from_u8(value: u8) -> Result<Self, Error> {
+        match value {
+            0 => Ok(Self::Aes128Gcm),
+            _ => Err(Error::InvalidKeystoreFormat("unknown cipher identifier")),
+        }
+    }
+}
+
+/// Key-derivation function and parameters used to turn a passphrase into an
+/// AES key and nonce for [`StacksWallet::encrypt_key`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum KdfParams {
+    /// PBKDF2-HMAC-SHA512 with the given iteration count.
+    Pbkdf2HmacSha512 { iterations: NonZeroU32 },
+    /// scrypt with the given cost (`N = 2^log_n`), block size `r`, and
+    /// parallelization `p`.
+    Scrypt { log_n: u8, r: u32, p: u32 },
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        #[allow(clippy::unwrap_used)]
+        Self::Pbkdf2HmacSha512 {
+            iterations: NonZeroU32::new(DEFAULT_PBKDF2_ITERATIONS).unwrap(),
+        }
+    }
+}
+
+impl KdfParams {
+    /// scrypt with the crate's default cost parameters (`N = 2^15`, `r = 8`, `p = 1`),
+    /// a good security/latency balance for interactive wallet unlock.
+    pub const fn default_scrypt() -> Self {
+        Self::Scrypt {
+            log_n: DEFAULT_SCRYPT_LOG_N,
+            r: DEFAULT_SCRYPT_R,
+            p: DEFAULT_SCRYPT_P,
+        }
+    }
+
+    fn id(&self) -> KdfId {
+        match self {
+            Self::Pbkdf2HmacSha512 { .. } => KdfId::Pbkdf2HmacSha512,
+            Self::Scrypt { .. } => KdfId::Scrypt,
+        }
+    }
+
+    /// Serializes the KDF-specific parameters (not including the [`KdfId`]).
+    fn write_params(&self, out: &mut Vec<u8>) {
+        match self {
+            Self::Pbkdf2HmacSha512 { iterations } => {
+                out.extend_from_slice(&iterations.get().to_le_bytes());
+            }
+            Self::Scrypt { log_n, r, p } => {
+                out.push(*log_n);
+                out.extend_from_slice(&r.to_le_bytes());
+                out.extend_from_slice(&p.to_le_bytes());
+            }
+        }
+    }
+
+    /// Parses the KDF-specific parameters following a [`KdfId`], returning the
+    /// params and the number of bytes consumed from `data`.
+    fn read_params(id: KdfId, data: &[u8]) -> Result<(Self, usize), Error> {
+        match id {
+            KdfId::Pbkdf2HmacSha512 => {
+                let bytes: [u8; 4] = data
+                    .get(..4)
+                    .ok_or(Error::InvalidKeystoreFormat("truncated KDF params"))?
+                    .try_into()?;
+                let iterations = NonZeroU32::new(u32::from_le_bytes(bytes))
+                    .ok_or(Error::InvalidKeystoreFormat("zero PBKDF2 iteration count"))?;
+                Ok((Self::Pbkdf2HmacSha512 { iterations }, 4))
+            }
+            KdfId::Scrypt => {
+                let log_n = *data
+                    .first()
+                    .ok_or(Error::InvalidKeystoreFormat("truncated KDF params"))?;
+                let r_bytes: [u8; 4] = data
+                    .get(1..5)
+                    .ok_or(Error::InvalidKeystoreFormat("truncated KDF params"))?
+                    .try_into()?;
+                let p_bytes: [u8; 4] = data
+                    .get(5..9)
+                    .ok_or(Error::InvalidKeystoreFormat("truncated KDF params"))?
+                    .try_into()?;
+                let r = u32::from_le_bytes(r_bytes);
+                let p = u32::from_le_bytes(p_bytes);
+                Ok((Self::Scrypt { log_n, r, p }, 9))
+            }
+        }
+    }
+
+    /// Derives the 16-byte AES key and 12-byte AES-GCM nonce for `passphrase`.
+    ///
+    /// The result is wrapped in [`Zeroizing`] so the derived key material is
+    /// scrubbed as soon as the caller is done with it.
+    fn derive(
+        &self,
+        passphrase: &[u8],
+        salt: &[u8],
+    ) -> Result<Zeroizing<[u8; 16 + aead::NONCE_LEN]>, Error> {
+        let mut key_and_nonce = Zeroizing::new([0u8; 16 + aead::NONCE_LEN]);
+
+        match self {
+            Self::Pbkdf2HmacSha512 { iterations } => {
+                pbkdf2::derive(
+                    pbkdf2::PBKDF2_HMAC_SHA512,
+                    *iterations,
+                    salt,
+                    passphrase,
+                    &mut key_and_nonce,
+                );
+            }
+            Self::Scrypt { log_n, r, p } => {
+                let params = ScryptParams::new(*log_n, *r, *p, key_and_nonce.len())
+                    .map_err(|_| Error::InvalidKeystoreFormat("invalid scrypt parameters"))?;
+                scrypt::scrypt(passphrase, salt, &params, &mut key_and_nonce)
+                    .map_err(|_| Error::InvalidKeystoreFormat("scrypt derivation failed"))?;
+            }
+        }
+
+        Ok(key_and_nonce)
+    }
+}
+
+/// Number of fixed characters (`S` plus the version byte) at the start of
+/// every c32 address produced by [`StacksAccount::get_address`].
+const C32_ADDRESS_FIXED_PREFIX_LEN: usize = 2;
+
+/// The alphabet used by Stacks' c32check encoding (matches `crate::crypto::c32_address`).
+const C32_ALPHABET: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Where a vanity pattern must match within a c32-encoded address, after the
+/// fixed `S<version>` prefix that [`StacksAccount::get_address`] always writes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VanityPattern {
+    /// The address must start with this literal pattern.
+    Prefix(String),
+    /// The address must end with this literal pattern.
+    Suffix(String),
+}
+
+impl VanityPattern {
+    fn pattern(&self) -> &str {
+        match self {
+            Self::Prefix(pattern) | Self::Suffix(pattern) => pattern,
+        }
+    }
+
+    fn matches(&self, address_body: &str) -> bool {
+        match self {
+            Self::Prefix(pattern) => address_body.starts_with(pattern.as_str()),
+            Self::Suffix(pattern) => address_body.ends_with(pattern.as_str()),
+        }
+    }
+}
+
+/// A non-secret placeholder key. `StacksPrivateKey` doesn't implement
+/// `Zeroize` itself, so this is substituted in place of a real `private_key`
+/// field on drop: it's the closest we can get to scrubbing the field without
+/// reaching into `StacksPrivateKey`'s own (unexported) representation.
+fn dummy_private_key() -> StacksPrivateKey {
+    #[allow(clippy::unwrap_used)]
+    StacksPrivateKey::from_slice(&[1u8; PRIVATE_KEY_BYTE_SIZE]).unwrap()
+}
+
+/// Default BIP44 gap limit: [`StacksWallet::discover_accounts`] stops after
+/// this many consecutive derivation indices show no on-chain activity.
+pub const DEFAULT_GAP_LIMIT: u32 = 20;
+
+pub type DiscoveredAccounts = std::collections::HashMap<u32, DiscoveredAccount>;
+
+/// Caller-supplied labels or other metadata for derived accounts, recorded
+/// alongside their indices in a full-wallet envelope (see
+/// [`StacksWallet::encrypt_wallet_with_kdf`]).
+pub type AccountLabels = std::collections::HashMap<u32, String>;
+
+/// A discovered account paired with its mainnet and testnet P2PKH/P2SH addresses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredAccount {
+    pub account: StacksAccount,
+    pub mainnet_p2pkh: String,
+    pub mainnet_p2sh: String,
+    pub testnet_p2pkh: String,
+    pub testnet_p2sh: String,
+}
+
+impl DiscoveredAccount {
+    fn from_account(account: StacksAccount) -> Result<Self, Error> {
+        Ok(Self {
+            mainnet_p2pkh: account.get_address(AddressVersion::MainnetP2PKH)?,
+            mainnet_p2sh: account.get_address(AddressVersion::MainnetP2SH)?,
+            testnet_p2pkh: account.get_address(AddressVersion::TestnetP2PKH)?,
+            testnet_p2sh: account.get_address(AddressVersion::TestnetP2SH)?,
+            account,
+        })
+    }
+}
+
+/// A `StacksAccount` instance, which contains a public key, a private key, and a derivation index.
+///
+/// Does not derive `Copy`: every copy of `private_key` would be a new,
+/// unscrubbed copy of the secret key that this type's `Drop` impl can't reach.
+/// On drop, `private_key` is overwritten in place with a non-secret
+/// placeholder (see [`dummy_private_key`]) so the account's storage doesn't
+/// keep holding the real key after it goes out of scope.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StacksAccount {
     pub index: u32,
     pub public_key: StacksPublicKey,
     pub private_key: StacksPrivateKey,
 }
 
+impl Drop for StacksAccount {
+    fn drop(&mut self) {
+        let _ = std::mem::replace(&mut self.private_key, dummy_private_key());
+    }
+}
+
 impl StacksAccount {
     /// Creates a new `StacksAccount`.
     fn new(index: u32, public_key: StacksPublicKey, private_key: StacksPrivateKey) -> Self {
@@ -49,15 +437,82 @@ impl StacksAccount {
         let c32 = c32_address(address.as_bytes(), version as u8)?;
         Ok(c32)
     }
+
+    /// Signs an arbitrary byte payload with this account's private key,
+    /// using the Stacks message-signing convention (see [`hash_message`]).
+    /// Returns a recoverable secp256k1 signature in VRS form.
+    pub fn sign_message(&self, message: &[u8]) -> Result<[u8; 65], Error> {
+        sign_recoverable_hash(&self.private_key, &hash_message(message))
+    }
+
+    /// Verifies a [`Self::sign_message`] signature against this account's
+    /// public key.
+    pub fn verify_message(&self, message: &[u8], signature: &[u8; 65]) -> Result<bool, Error> {
+        Self::verify_message_with_key(&self.public_key, message, signature)
+    }
+
+    /// Verifies a [`Self::sign_message`] signature against an arbitrary
+    /// public key, without needing a `StacksAccount`.
+    pub fn verify_message_with_key(
+        public_key: &StacksPublicKey,
+        message: &[u8],
+        signature: &[u8; 65],
+    ) -> Result<bool, Error> {
+        verify_recoverable_hash(public_key, &hash_message(message), signature)
+    }
+
+    /// Signs SIP-018 structured data with this account's private key.
+    ///
+    /// `domain_hash` and `message_hash` are the Clarity-value hashes of the
+    /// signing domain and payload (see [`hash_structured_data`]); combining
+    /// them before signing domain-separates the signature so it can't be
+    /// replayed across apps or networks. Returns a recoverable secp256k1
+    /// signature in VRS form.
+    pub fn sign_structured_data(
+        &self,
+        domain_hash: &[u8; 32],
+        message_hash: &[u8; 32],
+    ) -> Result<[u8; 65], Error> {
+        sign_recoverable_hash(
+            &self.private_key,
+            &hash_structured_data(domain_hash, message_hash),
+        )
+    }
+
+    /// Verifies a [`Self::sign_structured_data`] signature against this
+    /// account's public key.
+    pub fn verify_structured_data(
+        &self,
+        domain_hash: &[u8; 32],
+        message_hash: &[u8; 32],
+        signature: &[u8; 65],
+    ) -> Result<bool, Error> {
+        verify_recoverable_hash(
+            &self.public_key,
+            &hash_structured_data(domain_hash, message_hash),
+            signature,
+        )
+    }
 }
 
 /// A parent `StacksWallet`, which contains a root key and a map of derived accounts.
+///
+/// On drop, `root_key`'s `chain_code` is zeroized in place (it's a plain byte
+/// array, so [`Zeroize`] applies directly) and `private_key` is overwritten
+/// with a non-secret placeholder, same as [`StacksAccount`]'s `Drop` impl.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StacksWallet {
     root_key: ExtendedPrivateKey,
     accounts: StacksAccounts,
 }
 
+impl Drop for StacksWallet {
+    fn drop(&mut self) {
+        self.root_key.chain_code.zeroize();
+        let _ = std::mem::replace(&mut self.root_key.private_key, dummy_private_key());
+    }
+}
+
 impl StacksWallet {
     /// Creates a new `StacksWallet`.
     fn new(root_key: ExtendedPrivateKey, accounts: StacksAccounts) -> Self {
@@ -77,10 +532,10 @@ impl StacksWallet {
     /// Gets an account by derivation index.
     pub fn get_account(&mut self, index: u32) -> Result<StacksAccount, Error> {
         if let Some(account) = self.accounts.get(&index) {
-            Ok(*account)
+            Ok(account.clone())
         } else {
             let account = StacksAccount::derive(&self.root_key, index)?;
-            self.set_account(index, account);
+            self.set_account(index, account.clone());
             Ok(account)
         }
     }
@@ -90,86 +545,463 @@ impl StacksWallet {
         self.accounts.insert(index, account);
     }
 
-    /// Encrypts the wallet with a passphrase.
-    pub fn encrypt_key(&self, passphrase: &str) -> Result<Vec<u8>, Error> {
-        let mut salt = [0u8; 16];
-        let mut rng = rand::thread_rng();
+    /// Searches derivation indices `0..max_attempts` for an account whose
+    /// `version`-encoded c32 address matches `pattern`, after the fixed
+    /// `S<version>` prefix every `get_address` result starts with.
+    ///
+    /// The search is split across the available CPUs; the account for the
+    /// lowest matching index is returned and cached via [`Self::set_account`].
+    /// Returns an error immediately if `pattern` contains characters outside
+    /// the c32 alphabet, since no derivation could ever satisfy it.
+    pub fn find_vanity_account(
+        &mut self,
+        version: AddressVersion,
+        pattern: VanityPattern,
+        max_attempts: u32,
+    ) -> Result<(StacksAccount, u32), Error> {
+        if pattern.pattern().is_empty()
+            || !pattern.pattern().chars().all(|c| C32_ALPHABET.contains(c))
+        {
+            return Err(Error::InvalidVanityPattern(
+                "pattern must be non-empty and use only c32 alphabet characters",
+            ));
+        }
+
+        let worker_count = u32::try_from(
+            std::thread::available_parallelism()
+                .map(std::num::NonZeroUsize::get)
+                .unwrap_or(1),
+        )
+        .unwrap_or(1)
+        .max(1);
+        let chunk = max_attempts.div_ceil(worker_count).max(1);
+        let root_key = &self.root_key;
+
+        let found = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..worker_count)
+                .map(|worker| {
+                    let start = worker.saturating_mul(chunk).min(max_attempts);
+                    let end = start.saturating_add(chunk).min(max_attempts);
+                    let pattern = &pattern;
+
+                    scope.spawn(move || {
+                        (start..end).find_map(|index| {
+                            let account = StacksAccount::derive(root_key, index).ok()?;
+                            let address = account.get_address(version).ok()?;
+                            let body = address.get(C32_ADDRESS_FIXED_PREFIX_LEN..)?;
+                            pattern.matches(body).then_some((account, index))
+                        })
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .filter_map(|handle| handle.join().ok().flatten())
+                .min_by_key(|(_, index)| *index)
+        });
+
+        let (account, index) = found.ok_or(Error::VanityPatternNotFound)?;
+        self.set_account(index, account.clone());
+        Ok((account, index))
+    }
+
+    /// Derives a contiguous range of accounts `[start, start + count)` in one
+    /// call, populating the internal cache so later `get_account` calls are free.
+    pub fn derive_accounts(
+        &mut self,
+        start: u32,
+        count: u32,
+    ) -> Result<DiscoveredAccounts, Error> {
+        let mut discovered = DiscoveredAccounts::new();
+        for index in start..start.saturating_add(count) {
+            let account = self.get_account(index)?;
+            discovered.insert(index, DiscoveredAccount::from_account(account)?);
+        }
+        Ok(discovered)
+    }
+
+    /// Walks derivation indices starting at 0, calling `has_activity` with
+    /// each account's mainnet P2PKH address, and stops after `gap_limit`
+    /// consecutive indices report no activity (the BIP44 gap-limit rule; pass
+    /// [`DEFAULT_GAP_LIMIT`] for the conventional value of 20). Returns every
+    /// account found to have activity, with the cache populated as it goes.
+    pub fn discover_accounts(
+        &mut self,
+        gap_limit: u32,
+        mut has_activity: impl FnMut(&str) -> Result<bool, Error>,
+    ) -> Result<DiscoveredAccounts, Error> {
+        let mut discovered = DiscoveredAccounts::new();
+        let mut consecutive_unused = 0u32;
+        let mut index = 0u32;
+
+        while consecutive_unused < gap_limit {
+            let account = self.get_account(index)?;
+            let candidate = DiscoveredAccount::from_account(account)?;
+
+            if has_activity(&candidate.mainnet_p2pkh)? {
+                consecutive_unused = 0;
+                discovered.insert(index, candidate);
+            } else {
+                consecutive_unused += 1;
+            }
 
-        salt.copy_from_slice(&rng.gen::<[u8; 16]>()[..]);
+            index += 1;
+        }
+
+        Ok(discovered)
+    }
+
+    /// Encrypts the wallet with a passphrase, using the default KDF (PBKDF2-HMAC-SHA512,
+    /// [`DEFAULT_PBKDF2_ITERATIONS`] iterations) and cipher ([`CipherId::default`]).
+    ///
+    /// Only the root key is persisted; derived accounts are discarded on
+    /// round-trip. Use [`Self::encrypt_wallet`] to keep them.
+    pub fn encrypt_key(&self, passphrase: impl Into<SafePassword>) -> Result<Vec<u8>, Error> {
+        self.encrypt_key_with_kdf(passphrase, KdfParams::default())
+    }
+
+    /// Encrypts the wallet with a passphrase using an explicit [`KdfParams`] choice
+    /// and the default cipher ([`CipherId::default`]).
+    ///
+    /// The resulting blob is a self-describing envelope: a magic byte, a format
+    /// version, the KDF identifier and its parameters, the cipher identifier, and
+    /// the salt length all precede the ciphertext, so `from_encrypted_key` can
+    /// reproduce the derivation without guessing.
+    pub fn encrypt_key_with_kdf(
+        &self,
+        passphrase: impl Into<SafePassword>,
+        kdf: KdfParams,
+    ) -> Result<Vec<u8>, Error> {
+        self.encrypt_key_with_params(passphrase, kdf, CipherId::default())
+    }
+
+    /// Encrypts the wallet with a passphrase using an explicit [`KdfParams`] and
+    /// [`CipherId`] choice.
+    pub fn encrypt_key_with_params(
+        &self,
+        passphrase: impl Into<SafePassword>,
+        kdf: KdfParams,
+        cipher: CipherId,
+    ) -> Result<Vec<u8>, Error> {
+        let mut plaintext = Zeroizing::new(Vec::new());
+        plaintext.extend_from_slice(&self.root_key.chain_code[..]);
+        plaintext.extend(self.root_key.private_key.secret_bytes());
+
+        Self::seal_envelope(
+            passphrase.into(),
+            kdf,
+            cipher,
+            KEYSTORE_VERSION_ROOT_ONLY,
+            &plaintext,
+        )
+    }
+
+    /// Encrypts the wallet, including every derived account currently in its
+    /// cache, with a passphrase, using the default KDF and cipher.
+    pub fn encrypt_wallet(&self, passphrase: impl Into<SafePassword>) -> Result<Vec<u8>, Error> {
+        self.encrypt_wallet_with_kdf(passphrase, KdfParams::default(), &AccountLabels::new())
+    }
+
+    /// Encrypts the wallet, including every derived account currently in its
+    /// cache (and the given `labels`, if any), with a passphrase using an
+    /// explicit [`KdfParams`] choice and the default cipher ([`CipherId::default`]).
+    ///
+    /// The account indices and labels are length-prefixed and placed inside
+    /// the same AEAD plaintext as the root key, so tampering with the
+    /// restored account set is detected alongside tampering with the key
+    /// itself. The envelope's version byte ([`KEYSTORE_VERSION_FULL`]) lets
+    /// it coexist with the root-only blobs written by [`Self::encrypt_key`].
+    pub fn encrypt_wallet_with_kdf(
+        &self,
+        passphrase: impl Into<SafePassword>,
+        kdf: KdfParams,
+        labels: &AccountLabels,
+    ) -> Result<Vec<u8>, Error> {
+        self.encrypt_wallet_with_params(passphrase, kdf, CipherId::default(), labels)
+    }
+
+    /// Encrypts the wallet, including every derived account currently in its
+    /// cache (and the given `labels`, if any), with a passphrase using an
+    /// explicit [`KdfParams`] and [`CipherId`] choice.
+    pub fn encrypt_wallet_with_params(
+        &self,
+        passphrase: impl Into<SafePassword>,
+        kdf: KdfParams,
+        cipher: CipherId,
+        labels: &AccountLabels,
+    ) -> Result<Vec<u8>, Error> {
+        let mut plaintext = Zeroizing::new(Vec::new());
+        plaintext.extend_from_slice(&self.root_key.chain_code[..]);
+        plaintext.extend(self.root_key.private_key.secret_bytes());
+
+        let mut indices: Vec<u32> = self.accounts.keys().copied().collect();
+        indices.sort_unstable();
 
         #[allow(clippy::unwrap_used)]
-        let n_iter = NonZeroU32::new(100_000).unwrap();
-        let mut key_and_nonce = [0u8; 16 + aead::NONCE_LEN];
-
-        pbkdf2::derive(
-            pbkdf2::PBKDF2_HMAC_SHA512,
-            n_iter,
-            &salt,
-            passphrase.as_bytes(),
-            &mut key_and_nonce,
-        );
+        plaintext.extend_from_slice(&u32::try_from(indices.len()).unwrap().to_le_bytes());
+        for index in indices {
+            plaintext.extend_from_slice(&index.to_le_bytes());
 
+            let label = labels.get(&index).map(String::as_str).unwrap_or("");
+            let label_len = u16::try_from(label.len())
+                .map_err(|_| Error::InvalidKeystoreFormat("label too long"))?;
+            plaintext.extend_from_slice(&label_len.to_le_bytes());
+            plaintext.extend_from_slice(label.as_bytes());
+        }
+
+        Self::seal_envelope(
+            passphrase.into(),
+            kdf,
+            cipher,
+            KEYSTORE_VERSION_FULL,
+            &plaintext,
+        )
+    }
+
+    /// Encrypts `plaintext` under `kdf` and `cipher`, and wraps it in a
+    /// self-describing envelope tagged with `version`.
+    fn seal_envelope(
+        passphrase: SafePassword,
+        kdf: KdfParams,
+        cipher: CipherId,
+        version: u8,
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let mut salt = [0u8; SALT_LEN];
+        let mut rng = rand::thread_rng();
+        salt.copy_from_slice(&rng.gen::<[u8; SALT_LEN]>()[..]);
+
+        let key_and_nonce = kdf.derive(passphrase.as_bytes(), &salt)?;
         let enc_key = &key_and_nonce[..16];
         let mut nonce = [0u8; aead::NONCE_LEN];
         nonce.copy_from_slice(&key_and_nonce[16..]);
 
+        // Only one cipher identifier exists today (AES-128-GCM); the `match`
+        // makes adding a second one a compile error here until it's wired up.
+        match cipher {
+            CipherId::Aes128Gcm => {}
+        }
         let key = aead::UnboundKey::new(&aead::AES_128_GCM, enc_key)?;
         let key = aead::LessSafeKey::new(key);
         let nonce = aead::Nonce::assume_unique_for_key(nonce);
 
-        let mut data = vec![0u8; 0];
-        data.extend_from_slice(&self.root_key.chain_code[..]);
-        data.extend(self.root_key.private_key.secret_bytes());
+        let mut data = Zeroizing::new(plaintext.to_vec());
+        key.seal_in_place_append_tag(nonce, aead::Aad::empty(), &mut *data)?;
 
-        key.seal_in_place_append_tag(nonce, aead::Aad::empty(), &mut data)?;
-
-        // result is salt + ciphertext + tag
-        let mut result = salt.to_vec();
+        // envelope: magic | version | kdf_id | kdf_params | cipher_id | salt_len | salt | ciphertext+tag
+        let mut result = vec![KEYSTORE_MAGIC, version, kdf.id() as u8];
+        kdf.write_params(&mut result);
+        result.push(cipher as u8);
+        #[allow(clippy::unwrap_used)]
+        result.push(u8::try_from(salt.len()).unwrap());
+        result.extend_from_slice(&salt);
         result.extend_from_slice(&data);
 
         Ok(result)
     }
 
     /// Creates a wallet from an encrypted key and a passphrase.
-    pub fn from_encrypted_key(passphrase: &str, data: &[u8]) -> Result<Self, Error> {
-        let salt = &data[..16];
-        let ciphertext = &data[16..];
+    ///
+    /// Parses the envelope header written by [`StacksWallet::encrypt_key`] (or
+    /// [`StacksWallet::encrypt_key_with_kdf`]) to recover the KDF and its
+    /// parameters, so blobs written with non-default iteration counts still
+    /// decrypt correctly. Only understands root-only envelopes; use
+    /// [`Self::from_encrypted_wallet`] for blobs written by `encrypt_wallet`.
+    pub fn from_encrypted_key(
+        passphrase: impl Into<SafePassword>,
+        data: &[u8],
+    ) -> Result<Self, Error> {
+        let header = Self::parse_envelope(data)?;
+        if header.version != KEYSTORE_VERSION_ROOT_ONLY {
+            return Err(Error::InvalidKeystoreFormat("unsupported keystore version"));
+        }
+        let plaintext = Self::open_envelope(passphrase.into(), &header)?;
+        Self::root_key_from_plaintext(&plaintext)
+    }
 
-        #[allow(clippy::unwrap_used)]
-        let n_iter = NonZeroU32::new(100_000).unwrap();
-        let mut key_and_nonce = [0u8; 16 + aead::NONCE_LEN];
+    /// Creates a wallet, and any account labels it was encrypted with, from
+    /// an encrypted blob and a passphrase.
+    ///
+    /// Accepts both root-only envelopes (written by `encrypt_key`, returned
+    /// with an empty account set and no labels) and full envelopes (written
+    /// by `encrypt_wallet`), dispatching on the envelope's version byte.
+    pub fn from_encrypted_wallet(
+        passphrase: impl Into<SafePassword>,
+        data: &[u8],
+    ) -> Result<(Self, AccountLabels), Error> {
+        let passphrase = passphrase.into();
+        let header = Self::parse_envelope(data)?;
 
-        pbkdf2::derive(
-            pbkdf2::PBKDF2_HMAC_SHA512,
-            n_iter,
-            salt,
-            passphrase.as_bytes(),
-            &mut key_and_nonce,
-        );
+        match header.version {
+            KEYSTORE_VERSION_ROOT_ONLY => {
+                let plaintext = Self::open_envelope(passphrase, &header)?;
+                let wallet = Self::root_key_from_plaintext(&plaintext)?;
+                Ok((wallet, AccountLabels::new()))
+            }
+            KEYSTORE_VERSION_FULL => {
+                let plaintext = Self::open_envelope(passphrase, &header)?;
+                Self::wallet_from_full_plaintext(&plaintext)
+            }
+            _ => Err(Error::InvalidKeystoreFormat("unsupported keystore version")),
+        }
+    }
 
-        let enc_key = &key_and_nonce[..16];
-        let mut nonce = [0u8; aead::NONCE_LEN];
-        nonce.copy_from_slice(&key_and_nonce[16..]);
+    /// Parses the root chain code and private key from a decrypted root-only
+    /// plaintext followed by its AEAD tag, as returned by `open_envelope`.
+    /// Full-wallet plaintexts are not accepted here: every byte between the
+    /// chain code and the tag is read as the private key.
+    fn root_key_from_plaintext(data: &[u8]) -> Result<Self, Error> {
+        let chain_code: [u8; KEY_BYTE_SIZE] = data[..KEY_BYTE_SIZE].try_into()?;
+        let private_key = StacksPrivateKey::from_slice(&data[KEY_BYTE_SIZE..data.len() - AEAD_TAG_LEN])?;
+        let root_key = ExtendedPrivateKey {
+            private_key,
+            chain_code,
+            depth: 0,
+        };
 
-        let key = aead::UnboundKey::new(&aead::AES_128_GCM, enc_key)?;
-        let key = aead::LessSafeKey::new(key);
-        let nonce = aead::Nonce::assume_unique_for_key(nonce);
+        Ok(Self::new(root_key, StacksAccounts::new()))
+    }
 
-        let mut data = ciphertext.to_vec();
-        key.open_in_place(nonce, aead::Aad::empty(), &mut data)?;
+    /// Parses the root key plus the length-prefixed account index/label list
+    /// from a decrypted full-wallet plaintext, re-deriving each account.
+    fn wallet_from_full_plaintext(data: &[u8]) -> Result<(Self, AccountLabels), Error> {
+        let plaintext_len = data
+            .len()
+            .checked_sub(AEAD_TAG_LEN)
+            .ok_or(Error::InvalidKeystoreFormat("truncated plaintext"))?;
 
         let chain_code: [u8; KEY_BYTE_SIZE] = data[..KEY_BYTE_SIZE].try_into()?;
-        let private_key = StacksPrivateKey::from_slice(&data[KEY_BYTE_SIZE..data.len() - 16])?;
+        let key_end = KEY_BYTE_SIZE + PRIVATE_KEY_BYTE_SIZE;
+        let private_key = StacksPrivateKey::from_slice(
+            data.get(KEY_BYTE_SIZE..key_end)
+                .ok_or(Error::InvalidKeystoreFormat("truncated private key"))?,
+        )?;
         let root_key = ExtendedPrivateKey {
             private_key,
             chain_code,
             depth: 0,
         };
 
-        Ok(Self::new(root_key, StacksAccounts::new()))
+        let mut wallet = Self::new(root_key, StacksAccounts::new());
+        let mut labels = AccountLabels::new();
+        let mut cursor = key_end;
+
+        let count = u32::from_le_bytes(
+            data.get(cursor..cursor + 4)
+                .ok_or(Error::InvalidKeystoreFormat("truncated account count"))?
+                .try_into()?,
+        );
+        cursor += 4;
+
+        for _ in 0..count {
+            let index = u32::from_le_bytes(
+                data.get(cursor..cursor + 4)
+                    .ok_or(Error::InvalidKeystoreFormat("truncated account index"))?
+                    .try_into()?,
+            );
+            cursor += 4;
+
+            let label_len = u16::from_le_bytes(
+                data.get(cursor..cursor + 2)
+                    .ok_or(Error::InvalidKeystoreFormat("truncated label length"))?
+                    .try_into()?,
+            ) as usize;
+            cursor += 2;
+
+            let label_bytes = data
+                .get(cursor..cursor + label_len)
+                .ok_or(Error::InvalidKeystoreFormat("truncated label"))?;
+            let label = String::from_utf8(label_bytes.to_vec())
+                .map_err(|_| Error::InvalidKeystoreFormat("label is not valid UTF-8"))?;
+            cursor += label_len;
+
+            let account = StacksAccount::derive(&wallet.root_key, index)?;
+            wallet.set_account(index, account);
+            if !label.is_empty() {
+                labels.insert(index, label);
+            }
+        }
+
+        if cursor != plaintext_len {
+            return Err(Error::InvalidKeystoreFormat("trailing bytes after account list"));
+        }
+
+        Ok((wallet, labels))
+    }
+
+    /// Parses the envelope header (magic, version, KDF id/params, cipher id,
+    /// salt) from an encrypted blob, without decrypting anything.
+    fn parse_envelope(data: &[u8]) -> Result<EnvelopeHeader<'_>, Error> {
+        if data.len() < 2 || data[0] != KEYSTORE_MAGIC {
+            return Err(Error::InvalidKeystoreFormat("bad magic byte"));
+        }
+        let version = data[1];
+        let rest = &data[2..];
+
+        let kdf_id = *rest
+            .first()
+            .ok_or(Error::InvalidKeystoreFormat("truncated header"))?;
+        let kdf_id = KdfId::from_u8(kdf_id)?;
+        let (kdf, consumed) = KdfParams::read_params(kdf_id, &rest[1..])?;
+        let rest = &rest[1 + consumed..];
+
+        let cipher_id = *rest
+            .first()
+            .ok_or(Error::InvalidKeystoreFormat("truncated header"))?;
+        let _cipher_id = CipherId::from_u8(cipher_id)?;
+        let rest = &rest[1..];
+
+        let salt_len = *rest
+            .first()
+            .ok_or(Error::InvalidKeystoreFormat("truncated header"))? as usize;
+        let rest = &rest[1..];
+
+        if rest.len() < salt_len {
+            return Err(Error::InvalidKeystoreFormat("truncated salt"));
+        }
+        let salt = &rest[..salt_len];
+        let ciphertext = &rest[salt_len..];
+
+        Ok(EnvelopeHeader {
+            version,
+            kdf,
+            salt,
+            ciphertext,
+        })
+    }
+
+    /// Derives the AES key/nonce for `header` from `passphrase` and decrypts
+    /// its ciphertext in place, returning the authenticated plaintext still
+    /// followed by its [`AEAD_TAG_LEN`]-byte tag (callers strip the tag).
+    fn open_envelope(
+        passphrase: SafePassword,
+        header: &EnvelopeHeader<'_>,
+    ) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let key_and_nonce = header.kdf.derive(passphrase.as_bytes(), header.salt)?;
+        let enc_key = &key_and_nonce[..16];
+        let mut nonce = [0u8; aead::NONCE_LEN];
+        nonce.copy_from_slice(&key_and_nonce[16..]);
+
+        let key = aead::UnboundKey::new(&aead::AES_128_GCM, enc_key)?;
+        let key = aead::LessSafeKey::new(key);
+        let nonce = aead::Nonce::assume_unique_for_key(nonce);
+
+        let mut data = Zeroizing::new(header.ciphertext.to_vec());
+        key.open_in_place(nonce, aead::Aad::empty(), &mut *data)?;
+
+        Ok(data)
     }
 }
 
+/// A parsed-but-not-yet-decrypted keystore envelope header.
+struct EnvelopeHeader<'a> {
+    version: u8,
+    kdf: KdfParams,
+    salt: &'a [u8],
+    ciphertext: &'a [u8],
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,4 +1058,195 @@ mod tests {
         let wallet2 = StacksWallet::from_encrypted_key("hello world", &data).unwrap();
         assert_eq!(wallet2, wallet);
     }
+
+    #[test]
+    fn encrypt_key_with_explicit_iterations() {
+        let secret_key = "sound idle panel often situate develop unit text design antenna vendor screen opinion balcony share trigger accuse scatter visa uniform brass update opinion media";
+        let wallet = StacksWallet::from_secret_key(secret_key).unwrap();
+
+        #[allow(clippy::unwrap_used)]
+        let kdf = KdfParams::Pbkdf2HmacSha512 {
+            iterations: NonZeroU32::new(1_000).unwrap(),
+        };
+        let data = wallet.encrypt_key_with_kdf("hello world", kdf).unwrap();
+        let wallet2 = StacksWallet::from_encrypted_key("hello world", &data).unwrap();
+        assert_eq!(wallet2, wallet);
+    }
+
+    #[test]
+    fn encrypt_key_with_scrypt() {
+        let secret_key = "sound idle panel often situate develop unit text design antenna vendor screen opinion balcony share trigger accuse scatter visa uniform brass update opinion media";
+        let wallet = StacksWallet::from_secret_key(secret_key).unwrap();
+
+        let kdf = KdfParams::Scrypt {
+            log_n: 10,
+            r: 8,
+            p: 1,
+        };
+        let data = wallet.encrypt_key_with_kdf("hello world", kdf).unwrap();
+        let wallet2 = StacksWallet::from_encrypted_key("hello world", &data).unwrap();
+        assert_eq!(wallet2, wallet);
+    }
+
+    #[test]
+    fn find_vanity_account_matches_prefix() {
+        let secret_key = "sound idle panel often situate develop unit text design antenna vendor screen opinion balcony share trigger accuse scatter visa uniform brass update opinion media";
+        let mut wallet = StacksWallet::from_secret_key(secret_key).unwrap();
+
+        let (account, index) = wallet
+            .find_vanity_account(
+                AddressVersion::MainnetP2PKH,
+                VanityPattern::Prefix("23K".to_string()),
+                10,
+            )
+            .unwrap();
+
+        assert_eq!(index, 1);
+        assert_eq!(wallet.get_account(index).unwrap(), account);
+    }
+
+    #[test]
+    fn find_vanity_account_rejects_invalid_pattern() {
+        let secret_key = "sound idle panel often situate develop unit text design antenna vendor screen opinion balcony share trigger accuse scatter visa uniform brass update opinion media";
+        let mut wallet = StacksWallet::from_secret_key(secret_key).unwrap();
+
+        let result = wallet.find_vanity_account(
+            AddressVersion::MainnetP2PKH,
+            VanityPattern::Prefix("LLL".to_string()),
+            10,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn find_vanity_account_reports_not_found_when_budget_is_exhausted() {
+        let secret_key = "sound idle panel often situate develop unit text design antenna vendor screen opinion balcony share trigger accuse scatter visa uniform brass update opinion media";
+        let mut wallet = StacksWallet::from_secret_key(secret_key).unwrap();
+
+        let result = wallet.find_vanity_account(
+            AddressVersion::MainnetP2PKH,
+            VanityPattern::Suffix("ZZZZZZZZ".to_string()),
+            1,
+        );
+
+        assert!(matches!(result, Err(Error::VanityPatternNotFound)));
+    }
+
+    #[test]
+    fn derive_accounts_populates_cache() {
+        let secret_key = "sound idle panel often situate develop unit text design antenna vendor screen opinion balcony share trigger accuse scatter visa uniform brass update opinion media";
+        let mut wallet = StacksWallet::from_secret_key(secret_key).unwrap();
+
+        let discovered = wallet.derive_accounts(0, 3).unwrap();
+        assert_eq!(discovered.len(), 3);
+        for index in 0..3 {
+            assert_eq!(wallet.get_account(index).unwrap(), discovered[&index].account);
+        }
+    }
+
+    #[test]
+    fn discover_accounts_stops_at_gap_limit() {
+        let secret_key = "sound idle panel often situate develop unit text design antenna vendor screen opinion balcony share trigger accuse scatter visa uniform brass update opinion media";
+        let mut wallet = StacksWallet::from_secret_key(secret_key).unwrap();
+
+        let discovered = wallet
+            .discover_accounts(2, |_address| Ok(false))
+            .unwrap();
+
+        assert!(discovered.is_empty());
+        // Only indices 0 and 1 were probed (and cached) before the walk stopped.
+        assert_eq!(wallet.accounts.len(), 2);
+    }
+
+    #[test]
+    fn discover_accounts_keeps_active_accounts() {
+        let secret_key = "sound idle panel often situate develop unit text design antenna vendor screen opinion balcony share trigger accuse scatter visa uniform brass update opinion media";
+        let mut wallet = StacksWallet::from_secret_key(secret_key).unwrap();
+
+        let active_address = wallet
+            .get_account(0)
+            .unwrap()
+            .get_address(AddressVersion::MainnetP2PKH)
+            .unwrap();
+
+        let discovered = wallet
+            .discover_accounts(2, |address| Ok(address == active_address))
+            .unwrap();
+
+        assert_eq!(discovered.len(), 1);
+        assert!(discovered.contains_key(&0));
+    }
+
+    #[test]
+    fn encrypt_wallet_round_trips_accounts_and_labels() {
+        let secret_key = "sound idle panel often situate develop unit text design antenna vendor screen opinion balcony share trigger accuse scatter visa uniform brass update opinion media";
+        let mut wallet = StacksWallet::from_secret_key(secret_key).unwrap();
+        wallet.get_account(0).unwrap();
+        wallet.get_account(3).unwrap();
+
+        let mut labels = AccountLabels::new();
+        labels.insert(3, "savings".to_string());
+
+        let data = wallet
+            .encrypt_wallet_with_kdf("hello world", KdfParams::default(), &labels)
+            .unwrap();
+        let (wallet2, labels2) = StacksWallet::from_encrypted_wallet("hello world", &data).unwrap();
+
+        assert_eq!(wallet2, wallet);
+        assert_eq!(labels2.get(&3), Some(&"savings".to_string()));
+        assert_eq!(labels2.get(&0), None);
+    }
+
+    #[test]
+    fn from_encrypted_wallet_accepts_root_only_blobs() {
+        let secret_key = "sound idle panel often situate develop unit text design antenna vendor screen opinion balcony share trigger accuse scatter visa uniform brass update opinion media";
+        let wallet = StacksWallet::from_secret_key(secret_key).unwrap();
+
+        let data = wallet.encrypt_key("hello world").unwrap();
+        let (wallet2, labels) = StacksWallet::from_encrypted_wallet("hello world", &data).unwrap();
+
+        assert_eq!(wallet2, wallet);
+        assert!(labels.is_empty());
+    }
+
+    #[test]
+    fn sign_message_round_trips() {
+        let secret_key = "sound idle panel often situate develop unit text design antenna vendor screen opinion balcony share trigger accuse scatter visa uniform brass update opinion media";
+        let mut wallet = StacksWallet::from_secret_key(secret_key).unwrap();
+        let account = wallet.get_account(0).unwrap();
+
+        let signature = account.sign_message(b"hello world").unwrap();
+
+        assert!(account.verify_message(b"hello world", &signature).unwrap());
+        assert!(!account.verify_message(b"goodbye world", &signature).unwrap());
+        assert!(StacksAccount::verify_message_with_key(
+            &account.public_key,
+            b"hello world",
+            &signature
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn sign_structured_data_round_trips() {
+        let secret_key = "sound idle panel often situate develop unit text design antenna vendor screen opinion balcony share trigger accuse scatter visa uniform brass update opinion media";
+        let mut wallet = StacksWallet::from_secret_key(secret_key).unwrap();
+        let account = wallet.get_account(0).unwrap();
+
+        let domain_hash = [1u8; 32];
+        let message_hash = [2u8; 32];
+        let other_message_hash = [3u8; 32];
+
+        let signature = account
+            .sign_structured_data(&domain_hash, &message_hash)
+            .unwrap();
+
+        assert!(account
+            .verify_structured_data(&domain_hash, &message_hash, &signature)
+            .unwrap());
+        assert!(!account
+            .verify_structured_data(&domain_hash, &other_message_hash, &signature)
+            .unwrap());
+    }
 }